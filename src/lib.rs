@@ -20,6 +20,40 @@
 //!     assert_eq!(vec.get(index), 1023);
 //! }
 //! ```
+use std::collections::TryReserveError;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when constructing a [`BitLongVec`] fails.
+///
+/// Mirrors the standard library's fallible-allocation errors so that callers
+/// dealing with untrusted or attacker-controlled capacities (e.g. parsing a
+/// chunk format with a capacity field from the wire) can recover instead of
+/// aborting the process.
+#[derive(Debug)]
+pub enum CapacityError {
+    /// `capacity * bits_per_value` overflowed while computing the required
+    /// number of longs.
+    CapacityOverflow,
+    /// The underlying allocation failed.
+    Alloc(TryReserveError),
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CapacityError::CapacityOverflow => write!(f, "capacity overflow"),
+            CapacityError::Alloc(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for CapacityError {}
+
+///
+/// # Invariant
+///
+/// Bits in the final long beyond `capacity * bits_per_value` are always zero.
 #[derive(Debug, PartialEq)]
 pub struct BitLongVec {
     /// Capacity of array.
@@ -37,11 +71,12 @@ impl BitLongVec {
     ///
     /// # Panics
     ///
-    /// Panics if `bits_per_value` is greater or equals 64.
+    /// Panics if `bits_per_value` is greater or equals 64 or `capacity *
+    /// bits_per_value` overflows.
     pub fn with_fixed_capacity(capacity: usize, bits_per_value: u8) -> Self {
         assert!(64 > bits_per_value, "Bit per value must be less than 64");
 
-        let longs_required = ((capacity * bits_per_value as usize) as f64 / 64.0).ceil() as usize;
+        let longs_required = longs_required(capacity, bits_per_value).expect("Capacity overflow");
         let max_possible_value = (1 << bits_per_value as u64) - 1;
         let data = vec![0u64; longs_required]; // <- Fastest way to initialize a vector.
 
@@ -55,22 +90,146 @@ impl BitLongVec {
 
     /// Create vector from long array.
     ///
+    /// Trailing bits in `data` beyond `capacity * bits_per_value` are masked
+    /// to zero to uphold the invariant documented on [`BitLongVec`].
+    ///
     /// # Panics
     ///
-    /// Panics if `bits_per_value` >= 64 or data length not match capacity.
+    /// Panics if `bits_per_value` >= 64, `capacity * bits_per_value`
+    /// overflows, or data length not match capacity.
     pub fn from_data(data: Vec<u64>, capacity: usize, bits_per_value: u8) -> Self {
         assert!(64 > bits_per_value, "Bit per value must be less than 64");
-        let longs_required = ((capacity * bits_per_value as usize) as f64 / 64.0).ceil() as usize;
+        let longs_required = longs_required(capacity, bits_per_value).expect("Capacity overflow");
         assert_eq!(longs_required, data.len(), "Data length not match capacity");
 
         let max_possible_value = (1 << bits_per_value as u64) - 1;
 
-        BitLongVec {
+        let mut vec = BitLongVec {
+            capacity,
+            bits_per_value,
+            max_possible_value,
+            data,
+        };
+        vec.fix_last_word();
+        vec
+    }
+
+    /// Create vector from a flat little-endian byte buffer, as produced by [`BitLongVec::to_bytes`].
+    ///
+    /// Trailing bits in `bytes` beyond `capacity * bits_per_value` are masked
+    /// to zero to uphold the invariant documented on [`BitLongVec`] — this
+    /// matters when `bytes` comes from an untrusted source, e.g. a
+    /// wire-format chunk palette.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_per_value` >= 64, `capacity * bits_per_value`
+    /// overflows, or `bytes` length not match capacity.
+    pub fn from_bytes(bytes: &[u8], capacity: usize, bits_per_value: u8) -> Self {
+        assert!(64 > bits_per_value, "Bit per value must be less than 64");
+        let longs_required = longs_required(capacity, bits_per_value).expect("Capacity overflow");
+        assert_eq!(
+            longs_required * 8,
+            bytes.len(),
+            "Bytes length not match capacity"
+        );
+
+        let data = bytes
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut long_bytes = [0u8; 8];
+                long_bytes.copy_from_slice(chunk);
+                u64::from_le_bytes(long_bytes)
+            })
+            .collect();
+
+        let max_possible_value = (1 << bits_per_value as u64) - 1;
+
+        let mut vec = BitLongVec {
             capacity,
             bits_per_value,
             max_possible_value,
             data,
+        };
+        vec.fix_last_word();
+        vec
+    }
+
+    /// Serialize the internal storage to a flat little-endian byte buffer.
+    ///
+    /// The resulting buffer is `longs_required * 8` bytes long and can be
+    /// turned back into a vector with [`BitLongVec::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.data.len() * 8);
+
+        for long in &self.data {
+            bytes.extend_from_slice(&long.to_le_bytes());
         }
+
+        bytes
+    }
+
+    /// Create a fixed capacity vector, without aborting on allocation failure.
+    ///
+    /// Like [`BitLongVec::with_fixed_capacity`], but reports an error instead
+    /// of aborting the process when the required storage cannot be allocated
+    /// (e.g. when `capacity` comes from untrusted input).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_per_value` is greater or equals 64.
+    pub fn try_with_fixed_capacity(
+        capacity: usize,
+        bits_per_value: u8,
+    ) -> Result<Self, CapacityError> {
+        assert!(64 > bits_per_value, "Bit per value must be less than 64");
+
+        let longs_required =
+            longs_required(capacity, bits_per_value).ok_or(CapacityError::CapacityOverflow)?;
+        let max_possible_value = (1 << bits_per_value as u64) - 1;
+
+        let mut data = Vec::new();
+        data.try_reserve_exact(longs_required)
+            .map_err(CapacityError::Alloc)?;
+        data.resize(longs_required, 0);
+
+        Ok(BitLongVec {
+            capacity,
+            bits_per_value,
+            max_possible_value,
+            data,
+        })
+    }
+
+    /// Create vector from long array, reporting capacity overflow as an error.
+    ///
+    /// Like [`BitLongVec::from_data`], but reports a [`CapacityError`]
+    /// instead of panicking when `capacity * bits_per_value` overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_per_value` >= 64 or data length not match capacity.
+    pub fn try_from_data(
+        data: Vec<u64>,
+        capacity: usize,
+        bits_per_value: u8,
+    ) -> Result<Self, CapacityError> {
+        assert!(64 > bits_per_value, "Bit per value must be less than 64");
+
+        let longs_required =
+            longs_required(capacity, bits_per_value).ok_or(CapacityError::CapacityOverflow)?;
+        assert_eq!(longs_required, data.len(), "Data length not match capacity");
+
+        let max_possible_value = (1 << bits_per_value as u64) - 1;
+
+        let mut vec = BitLongVec {
+            capacity,
+            bits_per_value,
+            max_possible_value,
+            data,
+        };
+        vec.fix_last_word();
+        Ok(vec)
     }
 
     /// Sets the `value` in the` index` position.
@@ -101,6 +260,72 @@ impl BitLongVec {
         }
     }
 
+    /// Sets every value in the vector to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` exceeds maximum.
+    pub fn fill(&mut self, value: u64) {
+        self.set_range(0, self.capacity, value);
+    }
+
+    /// Sets every value in `start..end` to `value`.
+    ///
+    /// Writes whole longs at once when `bits_per_value` divides 64 evenly,
+    /// falling back to the bit-splicing [`BitLongVec::set`] only at the
+    /// unaligned boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` is out of bounds or `value` exceeds maximum.
+    pub fn set_range(&mut self, start: usize, end: usize, value: u64) {
+        assert!(self.capacity >= end, "Index out of bounds");
+        assert!(self.max_possible_value >= value, "Value exceeds maximum");
+
+        let bits_per_value = self.bits_per_value as usize;
+
+        // `bits_per_value == 0` is the degenerate zero-width case: there is no
+        // storage backing any value (`self.data` is empty for any capacity),
+        // so every value is already 0 and there is nothing to write.
+        if bits_per_value == 0 {
+            return;
+        }
+
+        if 64 % bits_per_value == 0 {
+            let values_per_long = 64 / bits_per_value;
+            let mut index = start;
+
+            // Splice the values before the first long boundary.
+            while index < end && !index.is_multiple_of(values_per_long) {
+                self.set(index, value);
+                index += 1;
+            }
+
+            let mut pattern = 0u64;
+            for i in 0..values_per_long {
+                pattern |= value << (i * bits_per_value) as u64;
+            }
+
+            while index + values_per_long <= end {
+                let long_index = (index * bits_per_value) / 64;
+                self.data[long_index] = pattern;
+                index += values_per_long;
+            }
+
+            // Splice the remaining values after the last long boundary.
+            while index < end {
+                self.set(index, value);
+                index += 1;
+            }
+        } else {
+            for index in start..end {
+                self.set(index, value);
+            }
+        }
+
+        self.fix_last_word();
+    }
+
     /// Returns the `value` in the` index` position.
     ///
     /// # Panics
@@ -129,19 +354,150 @@ impl BitLongVec {
     ///
     /// Panics if `bits_per_value` >= 64 or `value` after resize exceeds maximum.
     pub fn resize(&self, bits_per_value: u8) -> BitLongVec {
-        let mut new_vec = BitLongVec::with_fixed_capacity(self.capacity, bits_per_value);
+        BitLongVecBuilder::new(bits_per_value).collect(self.iter())
+    }
+
+    /// Returns an iterator over the unpacked values, in order.
+    pub fn iter(&self) -> BitLongIter<'_> {
+        BitLongIter {
+            vec: self,
+            front: 0,
+            back: self.capacity,
+        }
+    }
+
+    /// Masks off the bits beyond `capacity * bits_per_value` in the final long.
+    ///
+    /// Upholds the invariant that unused trailing bits are always zero, so
+    /// that `PartialEq` and [`BitLongVec::to_bytes`] never depend on stale
+    /// high bits in storage handed to [`BitLongVec::from_data`],
+    /// [`BitLongVec::try_from_data`] or [`BitLongVec::from_bytes`].
+    fn fix_last_word(&mut self) {
+        let total_bits = self.capacity * self.bits_per_value as usize;
+        let bits_in_last_word = total_bits % 64;
+
+        if bits_in_last_word != 0 {
+            let mask = (1u64 << bits_in_last_word as u64) - 1;
+            let last_index = self.data.len() - 1;
+
+            self.data[last_index] &= mask;
+        }
+    }
+}
 
-        for index in 0..self.capacity {
-            new_vec.set(index, self.get(index));
+/// Iterator over the unpacked values of a [`BitLongVec`], yielded in order.
+///
+/// Created by [`BitLongVec::iter`].
+pub struct BitLongIter<'a> {
+    vec: &'a BitLongVec,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for BitLongIter<'a> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.front >= self.back {
+            return None;
         }
 
-        new_vec
+        let value = self.vec.get(self.front);
+        self.front += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+
+        (remaining, Some(remaining))
     }
 }
 
+impl<'a> ExactSizeIterator for BitLongIter<'a> {}
+
+impl<'a> DoubleEndedIterator for BitLongIter<'a> {
+    fn next_back(&mut self) -> Option<u64> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.vec.get(self.back))
+    }
+}
+
+impl<'a> IntoIterator for &'a BitLongVec {
+    type Item = u64;
+    type IntoIter = BitLongIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Builder for collecting an iterator of `u64` values into a [`BitLongVec`].
+///
+/// A plain [`FromIterator`](std::iter::FromIterator) implementation cannot
+/// be used here because `bits_per_value` is not part of the iterator item,
+/// so it is supplied to the builder up front instead.
+///
+/// # Example
+///
+/// ```
+/// use bit_long_vec::BitLongVecBuilder;
+///
+/// let vec = BitLongVecBuilder::new(10).collect((0..100usize).map(|value| value as u64));
+///
+/// for index in 0..100 {
+///     assert_eq!(vec.get(index), index as u64);
+/// }
+/// ```
+pub struct BitLongVecBuilder {
+    bits_per_value: u8,
+}
+
+impl BitLongVecBuilder {
+    /// Create a builder that packs collected values using `bits_per_value` bits each.
+    pub fn new(bits_per_value: u8) -> Self {
+        BitLongVecBuilder { bits_per_value }
+    }
+
+    /// Collect `iter` into a [`BitLongVec`], sizing capacity from the iterator's exact length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_per_value` >= 64 or any yielded value exceeds the maximum.
+    pub fn collect<I>(self, iter: I) -> BitLongVec
+    where
+        I: IntoIterator<Item = u64>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let capacity = iter.len();
+
+        let mut vec = BitLongVec::with_fixed_capacity(capacity, self.bits_per_value);
+
+        for (index, value) in iter.enumerate() {
+            vec.set(index, value);
+        }
+
+        vec
+    }
+}
+
+/// Number of `u64` longs needed to store `capacity` values of `bits_per_value`
+/// bits each, rounded up. Returns `None` on overflow.
+fn longs_required(capacity: usize, bits_per_value: u8) -> Option<usize> {
+    let total_bits = capacity.checked_mul(bits_per_value as usize)?;
+    Some(total_bits / 64 + if total_bits % 64 == 0 { 0 } else { 1 })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::BitLongVec;
+    use crate::{BitLongVec, BitLongVecBuilder, CapacityError};
 
     #[test]
     fn test_longs_required() {
@@ -265,6 +621,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_data_masks_dirty_trailing_bits() {
+        let clean = vec![11306972589037353624u64, 4224634284506261370];
+
+        let mut dirty = clean.clone();
+        let last_index = dirty.len() - 1;
+        dirty[last_index] |= 0xC000_0000_0000_0000; // Garbage in the 2 unused trailing bits.
+
+        let vec = BitLongVec::from_data(dirty, 9, 14);
+        let expected = BitLongVec::from_data(clean, 9, 14);
+
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_try_from_data_masks_dirty_trailing_bits() {
+        let clean = vec![11306972589037353624u64, 4224634284506261370];
+
+        let mut dirty = clean.clone();
+        let last_index = dirty.len() - 1;
+        dirty[last_index] |= 0xC000_0000_0000_0000; // Garbage in the 2 unused trailing bits.
+
+        let vec = BitLongVec::try_from_data(dirty, 9, 14).unwrap();
+        let expected = BitLongVec::try_from_data(clean, 9, 14).unwrap();
+
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let data = vec![17185, 34661, 52137];
+        let vec = BitLongVec::from_data(data, 48, 4);
+
+        let bytes = vec.to_bytes();
+
+        assert_eq!(bytes.len(), vec.data.len() * 8);
+        assert_eq!(&bytes[0..8], &17185u64.to_le_bytes());
+        assert_eq!(&bytes[8..16], &34661u64.to_le_bytes());
+        assert_eq!(&bytes[16..24], &52137u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let data = vec![17185, 34661, 52137];
+        let vec = BitLongVec::from_data(data, 48, 4);
+
+        let bytes = vec.to_bytes();
+        let from_bytes_vec = BitLongVec::from_bytes(&bytes, 48, 4);
+
+        assert_eq!(from_bytes_vec, vec);
+    }
+
+    #[test]
+    fn test_from_bytes_masks_dirty_trailing_bits() {
+        let clean = vec![11306972589037353624u64, 4224634284506261370];
+
+        let mut dirty = clean.clone();
+        let last_index = dirty.len() - 1;
+        dirty[last_index] |= 0xC000_0000_0000_0000; // Garbage in the 2 unused trailing bits.
+
+        let dirty_bytes: Vec<u8> = dirty.iter().flat_map(|long| long.to_le_bytes()).collect();
+
+        let vec = BitLongVec::from_bytes(&dirty_bytes, 9, 14);
+        let expected = BitLongVec::from_data(clean, 9, 14);
+
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_to_bytes_from_bytes_round_trip() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 14);
+
+        for index in 0..9 {
+            vec.set(index, 15_000 + index as u64);
+        }
+
+        let bytes = vec.to_bytes();
+        let round_tripped = BitLongVec::from_bytes(&bytes, 9, 14);
+
+        assert_eq!(round_tripped, vec);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bytes length not match capacity")]
+    fn test_from_bytes_length_not_match_capacity() {
+        BitLongVec::from_bytes(&[0; 8], 9, 14);
+    }
+
     #[test]
     #[should_panic(expected = "Bit per value must be less than 64")]
     fn test_with_fixed_capacity_bits_above_64() {
@@ -277,6 +721,18 @@ mod tests {
         BitLongVec::from_data(vec![], 1, 128);
     }
 
+    #[test]
+    #[should_panic(expected = "Capacity overflow")]
+    fn test_with_fixed_capacity_capacity_overflow() {
+        BitLongVec::with_fixed_capacity(usize::MAX, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow")]
+    fn test_from_data_capacity_overflow() {
+        BitLongVec::from_data(vec![], usize::MAX, 4);
+    }
+
     #[test]
     #[should_panic(expected = "Data length not match capacity")]
     fn test_from_data_length_not_match_capacity() {
@@ -304,6 +760,35 @@ mod tests {
         vec.get(100);
     }
 
+    #[test]
+    fn test_try_with_fixed_capacity() {
+        let vec = BitLongVec::try_with_fixed_capacity(2048, 4).unwrap();
+
+        assert_eq!(vec.data.len(), 128);
+        assert_eq!(vec.data.capacity(), 128);
+    }
+
+    #[test]
+    fn test_try_with_fixed_capacity_overflow() {
+        let result = BitLongVec::try_with_fixed_capacity(usize::MAX, 4);
+
+        assert!(matches!(result, Err(CapacityError::CapacityOverflow)));
+    }
+
+    #[test]
+    fn test_try_from_data() {
+        let vec = BitLongVec::try_from_data(vec![17185, 34661, 52137], 48, 4).unwrap();
+
+        assert_eq!(vec, BitLongVec::from_data(vec![17185, 34661, 52137], 48, 4));
+    }
+
+    #[test]
+    fn test_try_from_data_overflow() {
+        let result = BitLongVec::try_from_data(vec![], usize::MAX, 4);
+
+        assert!(matches!(result, Err(CapacityError::CapacityOverflow)));
+    }
+
     #[test]
     fn test_resize() {
         let mut vec = BitLongVec::with_fixed_capacity(15, 8);
@@ -319,6 +804,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_iter() {
+        let mut vec = BitLongVec::with_fixed_capacity(15, 8);
+
+        for index in 0..15 {
+            vec.set(index, index as u64 + 1);
+        }
+
+        let values: Vec<u64> = vec.iter().collect();
+        let expected: Vec<u64> = (1..=15).collect();
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_iter_exact_size() {
+        let vec = BitLongVec::with_fixed_capacity(9, 14);
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), 9);
+
+        iter.next();
+        assert_eq!(iter.len(), 8);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let mut vec = BitLongVec::with_fixed_capacity(15, 8);
+
+        for index in 0..15 {
+            vec.set(index, index as u64 + 1);
+        }
+
+        let values: Vec<u64> = vec.iter().rev().collect();
+        let expected: Vec<u64> = (1..=15).rev().collect();
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_into_iter_for_ref() {
+        let mut vec = BitLongVec::with_fixed_capacity(15, 8);
+
+        for index in 0..15 {
+            vec.set(index, index as u64 + 1);
+        }
+
+        let values: Vec<u64> = (&vec).into_iter().collect();
+        let expected: Vec<u64> = (1..=15).collect();
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_builder_collect() {
+        let vec = BitLongVecBuilder::new(10).collect((0..100usize).map(|value| value as u64));
+
+        assert_eq!(vec.capacity, 100);
+
+        for index in 0..100 {
+            assert_eq!(vec.get(index), index as u64);
+        }
+    }
+
+    #[test]
+    fn test_builder_collect_from_vec_into_iter() {
+        let values: Vec<u64> = (0..100).collect();
+        let vec = BitLongVecBuilder::new(10).collect(values);
+
+        assert_eq!(vec.capacity, 100);
+
+        for index in 0..100 {
+            assert_eq!(vec.get(index), index as u64);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Value exceeds maximum")]
     fn test_resize_value_exceeds_maximum() {
@@ -330,4 +891,80 @@ mod tests {
 
         vec.resize(4);
     }
+
+    #[test]
+    fn test_fill_aligned() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 8);
+        vec.fill(7);
+
+        for index in 0..9 {
+            assert_eq!(vec.get(index), 7);
+        }
+    }
+
+    #[test]
+    fn test_fill_unaligned() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 14);
+        vec.fill(12_345);
+
+        for index in 0..9 {
+            assert_eq!(vec.get(index), 12_345);
+        }
+    }
+
+    #[test]
+    fn test_fill_fixes_last_word() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 14);
+        vec.fill(vec.max_possible_value);
+
+        let last_index = vec.data.len() - 1;
+        let used_bits = (9 * 14) % 64;
+        let unused_bits_mask = !((1u64 << used_bits as u64) - 1);
+
+        assert_eq!(vec.data[last_index] & unused_bits_mask, 0);
+    }
+
+    #[test]
+    fn test_set_range() {
+        let mut vec = BitLongVec::with_fixed_capacity(15, 8);
+        vec.set(0, 1);
+        vec.set_range(1, 14, 9);
+        vec.set(14, 2);
+
+        assert_eq!(vec.get(0), 1);
+        for index in 1..14 {
+            assert_eq!(vec.get(index), 9);
+        }
+        assert_eq!(vec.get(14), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_set_range_out_of_bounds() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 8);
+        vec.set_range(0, 10, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value exceeds maximum")]
+    fn test_set_range_value_exceeds_maximum() {
+        let mut vec = BitLongVec::with_fixed_capacity(9, 4);
+        vec.set_range(0, 9, 16);
+    }
+
+    #[test]
+    fn test_fill_zero_bits_per_value() {
+        let mut vec = BitLongVec::with_fixed_capacity(5, 0);
+        vec.fill(0);
+
+        assert_eq!(vec.data, Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_set_range_zero_bits_per_value() {
+        let mut vec = BitLongVec::with_fixed_capacity(5, 0);
+        vec.set_range(1, 4, 0);
+
+        assert_eq!(vec.data, Vec::<u64>::new());
+    }
 }